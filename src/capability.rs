@@ -0,0 +1,50 @@
+//! Capability Registry.
+//!
+//! Holds the set of tools a host is willing to accept, each with a JSON
+//! Schema describing its expected `args` shape, so the validator can
+//! reject calls to unknown tools or calls with malformed arguments before
+//! they ever reach dispatch. Schemas are compiled with the same
+//! `jsonschema` engine [`crate::validation::Validator`] uses for its own
+//! built-in schemas, so the full JSON Schema vocabulary (`pattern`,
+//! `additionalProperties`, `minimum`/`maximum`, `$ref`, etc.) is honored
+//! here too.
+
+use std::collections::HashMap;
+
+/// Maps tool names to the JSON Schema their `args` must satisfy.
+///
+/// Empty by default: a `Validator` with no registry attached stays
+/// permissive, matching the crate's existing behavior.
+#[derive(Debug, Default)]
+pub struct CapabilityRegistry {
+    schemas: HashMap<String, jsonschema::Validator>,
+}
+
+impl CapabilityRegistry {
+    pub fn new() -> Self {
+        Self {
+            schemas: HashMap::new(),
+        }
+    }
+
+    /// Compiles and registers a tool's argument schema, overwriting any
+    /// prior schema for the same name.
+    pub fn register(
+        mut self,
+        name: impl Into<String>,
+        schema: serde_json::Value,
+    ) -> Result<Self, crate::validation::ValidationError> {
+        let compiled = jsonschema::validator_for(&schema)
+            .map_err(|e| crate::validation::ValidationError::InvalidSchema(e.to_string()))?;
+        self.schemas.insert(name.into(), compiled);
+        Ok(self)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.schemas.contains_key(name)
+    }
+
+    pub(crate) fn schema_for(&self, name: &str) -> Option<&jsonschema::Validator> {
+        self.schemas.get(name)
+    }
+}
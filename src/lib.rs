@@ -3,9 +3,14 @@
 //! Provides strict JSON schema validation for runtime messages according to
 //! FemtoClaw Protocol Specification (FC-03).
 
+pub mod capability;
 pub mod message;
 pub mod schema;
 pub mod validation;
 
-pub use message::{MessageContent, MessageForm, ProtocolOutput, ToolCallForm, ToolCallWrapper};
-pub use validation::{ValidationError, Validator};
+pub use capability::CapabilityRegistry;
+pub use message::{
+    ExtensionForm, Id, MessageContent, MessageForm, ProtocolOutput, ToolCallForm, ToolCallWrapper,
+    ToolCallsWrapper, ToolResultForm, ToolResultWrapper,
+};
+pub use validation::{ParsedOutcome, ToolChoice, ValidationError, Validator};
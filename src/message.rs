@@ -5,6 +5,25 @@
 
 use serde::{Deserialize, Serialize};
 
+/// A JSON-RPC-style correlation identifier.
+///
+/// Serialized untagged, exactly like jsonrpsee/tower-lsp: a bare number,
+/// a bare string, or `null`. `Null` is accepted for compatibility but its
+/// use is discouraged since it cannot correlate a result back to a call.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Id {
+    Number(i64),
+    String(String),
+    Null,
+}
+
+impl Id {
+    pub fn is_null(&self) -> bool {
+        matches!(self, Id::Null)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MessageContent {
     pub content: String,
@@ -22,6 +41,8 @@ pub struct ToolCallArgs {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ToolCallForm {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Id>,
     pub tool: String,
     pub args: serde_json::Value,
 }
@@ -31,11 +52,84 @@ pub struct ToolCallWrapper {
     pub tool_call: ToolCallForm,
 }
 
+/// A batch of independent tool calls emitted in a single protocol message,
+/// e.g. for parallel function calling.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolCallsWrapper {
+    pub tool_calls: Vec<ToolCallForm>,
+}
+
+/// The result of a previously issued tool call, reported back by the host.
+///
+/// Exactly one of `output` or `error` is present, mirroring the
+/// mutual-exclusivity rule the validator already enforces for
+/// `message`/`tool_call`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolResultForm {
+    pub id: Id,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolResultWrapper {
+    pub tool_result: ToolResultForm,
+}
+
+/// A top-level form registered at runtime via
+/// [`crate::validation::Validator::with_additional_schema`], validated
+/// against its caller-supplied JSON Schema rather than a built-in struct.
+///
+/// Unlike the other forms, its wire shape (`{"<name>": <value>}`) can't be
+/// expressed as a derived struct, so `Serialize`/`Deserialize` are
+/// hand-written below to keep it round-tripping like every other variant.
+#[derive(Debug, Clone)]
+pub struct ExtensionForm {
+    pub name: String,
+    pub value: serde_json::Value,
+}
+
+impl Serialize for ExtensionForm {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(&self.name, &self.value)?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ExtensionForm {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let map = serde_json::Map::<String, serde_json::Value>::deserialize(deserializer)?;
+        let mut entries = map.into_iter();
+        let (name, value) = entries
+            .next()
+            .ok_or_else(|| serde::de::Error::custom("extension form must have exactly one field"))?;
+        if entries.next().is_some() {
+            return Err(serde::de::Error::custom(
+                "extension form must have exactly one field",
+            ));
+        }
+        Ok(ExtensionForm { name, value })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ProtocolOutput {
     Message(MessageForm),
     ToolCall(ToolCallWrapper),
+    ToolCalls(ToolCallsWrapper),
+    ToolResult(ToolResultWrapper),
+    Extension(ExtensionForm),
 }
 
 impl ProtocolOutput {
@@ -52,10 +146,25 @@ impl ProtocolOutput {
         matches!(self, ProtocolOutput::ToolCall(_))
     }
 
+    pub fn is_tool_calls(&self) -> bool {
+        matches!(self, ProtocolOutput::ToolCalls(_))
+    }
+
+    pub fn is_tool_result(&self) -> bool {
+        matches!(self, ProtocolOutput::ToolResult(_))
+    }
+
+    pub fn is_extension(&self) -> bool {
+        matches!(self, ProtocolOutput::Extension(_))
+    }
+
     pub fn into_message(self) -> Option<String> {
         match self {
             ProtocolOutput::Message(m) => Some(m.message.content),
             ProtocolOutput::ToolCall(_) => None,
+            ProtocolOutput::ToolCalls(_) => None,
+            ProtocolOutput::ToolResult(_) => None,
+            ProtocolOutput::Extension(_) => None,
         }
     }
 
@@ -63,6 +172,34 @@ impl ProtocolOutput {
         match self {
             ProtocolOutput::Message(_) => None,
             ProtocolOutput::ToolCall(tc) => Some((tc.tool_call.tool, tc.tool_call.args)),
+            ProtocolOutput::ToolCalls(_) => None,
+            ProtocolOutput::ToolResult(_) => None,
+            ProtocolOutput::Extension(_) => None,
+        }
+    }
+
+    /// Unpacks a batched `tool_calls` form into per-call `(id, tool, args)`
+    /// tuples so a host can dispatch them concurrently.
+    pub fn into_tool_calls(self) -> Option<Vec<(Option<Id>, String, serde_json::Value)>> {
+        match self {
+            ProtocolOutput::ToolCalls(tcs) => Some(
+                tcs.tool_calls
+                    .into_iter()
+                    .map(|tc| (tc.id, tc.tool, tc.args))
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    /// The correlation id carried by a `tool_call` or `tool_result` form, if any.
+    pub fn correlation_id(&self) -> Option<&Id> {
+        match self {
+            ProtocolOutput::Message(_) => None,
+            ProtocolOutput::ToolCall(tc) => tc.tool_call.id.as_ref(),
+            ProtocolOutput::ToolCalls(_) => None,
+            ProtocolOutput::ToolResult(tr) => Some(&tr.tool_result.id),
+            ProtocolOutput::Extension(_) => None,
         }
     }
 }
@@ -102,4 +239,19 @@ mod tests {
         let result = ProtocolOutput::parse(input);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_extension_form_round_trips_through_wire_shape() {
+        let validator = crate::validation::Validator::new()
+            .with_additional_schema("ping", r#"{"type":"object"}"#)
+            .unwrap();
+        let input = serde_json::json!({"ping": {"nonce": 1}});
+        let output = validator.validate(&input).unwrap();
+
+        let serialized = serde_json::to_value(&output).unwrap();
+        assert_eq!(serialized, input);
+
+        let reparsed = validator.validate(&serialized).unwrap();
+        assert!(reparsed.is_extension());
+    }
 }
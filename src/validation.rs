@@ -2,8 +2,10 @@
 //!
 //! Implements strict validation according to FC-03 specification.
 //! Validator enforces:
-//! - Valid JSON structure
-//! - Correct protocol form (message OR tool_call, not both)
+//! - Valid JSON structure, checked against the compiled schemas in
+//!   [`crate::schema`] so the published schemas stay authoritative
+//! - Correct protocol form (exactly one of message, tool_call, tool_calls,
+//!   tool_result, or a registered extension form)
 //! - Valid capability identifier
 //! - Valid argument structure
 
@@ -21,19 +23,106 @@ pub enum ValidationError {
     InvalidFieldType(String),
     #[error("unknown field: {0}")]
     UnknownField(String),
+    #[error("unknown capability: {0}")]
+    UnknownCapability(String),
+    #[error("args for '{tool}' violate schema at '{path}': {reason}")]
+    ArgsSchemaViolation {
+        tool: String,
+        path: String,
+        reason: String,
+    },
+    #[error("invalid schema: {0}")]
+    InvalidSchema(String),
+}
+
+/// Constrains which form a model is allowed to respond with, mirroring how
+/// inference servers gate tool selection.
+///
+/// This only governs the choice between `message` and a tool call. Forms
+/// registered via [`Validator::with_additional_schema`] are out-of-band
+/// protocol extensions, not a competing answer to "did the model call a
+/// tool", so they are always exempt from `tool_choice` regardless of mode.
+/// A `tool_result` form is likewise neither a message nor a tool call and
+/// is accepted under every mode.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum ToolChoice {
+    /// Either a `message` or a tool call is allowed.
+    #[default]
+    Auto,
+    /// Only a `message` is allowed; any tool call is rejected.
+    None,
+    /// A tool call is required; a bare `message` is rejected.
+    Required,
+    /// Only the named tool may be called, in a `tool_call` or `tool_calls` form.
+    Function(String),
 }
 
 pub struct Validator {
     known_fields: Vec<&'static str>,
+    registry: Option<crate::capability::CapabilityRegistry>,
+    tool_choice: ToolChoice,
+    message_schema: jsonschema::Validator,
+    tool_call_schema: jsonschema::Validator,
+    additional_schemas: std::collections::HashMap<String, jsonschema::Validator>,
 }
 
 impl Validator {
     pub fn new() -> Self {
         Self {
-            known_fields: vec!["message", "tool_call"],
+            known_fields: vec!["message", "tool_call", "tool_calls", "tool_result"],
+            registry: None,
+            tool_choice: ToolChoice::Auto,
+            message_schema: compile_builtin_schema(crate::schema::MESSAGE_SCHEMA),
+            tool_call_schema: compile_builtin_schema(crate::schema::TOOL_CALL_SCHEMA),
+            additional_schemas: std::collections::HashMap::new(),
         }
     }
 
+    /// Opts into strict capability enforcement: tool calls must name a
+    /// registered tool, and their `args` must satisfy that tool's schema.
+    /// Without a registry, any non-empty tool name and object-shaped
+    /// `args` is accepted, preserving the crate's existing permissive
+    /// behavior.
+    pub fn with_registry(mut self, registry: crate::capability::CapabilityRegistry) -> Self {
+        self.registry = Some(registry);
+        self
+    }
+
+    /// Constrains which form(s) the validator will accept. Defaults to
+    /// [`ToolChoice::Auto`], which accepts any well-formed message.
+    pub fn with_tool_choice(mut self, tool_choice: ToolChoice) -> Self {
+        self.tool_choice = tool_choice;
+        self
+    }
+
+    /// Registers a new top-level message form under `name`, validated
+    /// structurally against `schema_str` (a JSON Schema document) without
+    /// forking the crate. The form becomes mutually exclusive with
+    /// `message`, `tool_call`, `tool_calls`, `tool_result`, and any other
+    /// registered extension. A well-formed instance is returned as
+    /// [`crate::message::ProtocolOutput::Extension`].
+    pub fn with_additional_schema(
+        mut self,
+        name: impl Into<String>,
+        schema_str: &str,
+    ) -> Result<Self, ValidationError> {
+        let name = name.into();
+
+        if self.known_fields.contains(&name.as_str()) || self.additional_schemas.contains_key(&name) {
+            return Err(ValidationError::InvalidSchema(format!(
+                "'{}' collides with a built-in or already-registered form name",
+                name
+            )));
+        }
+
+        let schema_value: serde_json::Value = serde_json::from_str(schema_str)
+            .map_err(|e| ValidationError::InvalidSchema(e.to_string()))?;
+        let compiled = jsonschema::validator_for(&schema_value)
+            .map_err(|e| ValidationError::InvalidSchema(e.to_string()))?;
+        self.additional_schemas.insert(name, compiled);
+        Ok(self)
+    }
+
     pub fn validate(
         &self,
         value: &serde_json::Value,
@@ -44,30 +133,56 @@ impl Validator {
 
         let has_message = obj.contains_key("message");
         let has_tool_call = obj.contains_key("tool_call");
-
-        if has_message && has_tool_call {
+        let has_tool_calls = obj.contains_key("tool_calls");
+        let has_tool_result = obj.contains_key("tool_result");
+        let extension_names: Vec<&String> = self
+            .additional_schemas
+            .keys()
+            .filter(|name| obj.contains_key(name.as_str()))
+            .collect();
+
+        let present_count = [has_message, has_tool_call, has_tool_calls, has_tool_result]
+            .iter()
+            .filter(|present| **present)
+            .count()
+            + extension_names.len();
+
+        if present_count != 1 {
             return Err(ValidationError::ProtocolViolation(
-                "protocol messages must contain exactly one of 'message' or 'tool_call', not both"
+                "protocol messages must contain exactly one of 'message', 'tool_call', 'tool_calls', 'tool_result' or a registered extension form"
                     .to_string(),
             ));
         }
 
-        if !has_message && !has_tool_call {
-            return Err(ValidationError::ProtocolViolation(
-                "protocol messages must contain either 'message' or 'tool_call'".to_string(),
-            ));
-        }
-
         for key in obj.keys() {
-            if !self.known_fields.contains(&key.as_str()) {
+            if !self.known_fields.contains(&key.as_str()) && !self.additional_schemas.contains_key(key) {
                 return Err(ValidationError::UnknownField(format!(
-                    "unknown field '{}' - protocol messages must only contain 'message' or 'tool_call'", key
+                    "unknown field '{}' - protocol messages must only contain 'message', 'tool_call', 'tool_calls', 'tool_result' or a registered extension form", key
                 )));
             }
         }
 
+        if let Some(name) = extension_names.into_iter().next() {
+            // Extension forms are out-of-band and exempt from `tool_choice`
+            // (see the doc comment on `ToolChoice`): they don't compete
+            // with `message`/tool calls for "did the model answer or act".
+            let schema = &self.additional_schemas[name];
+            check_schema(schema, &obj[name])?;
+            return Ok(crate::message::ProtocolOutput::Extension(
+                crate::message::ExtensionForm {
+                    name: name.clone(),
+                    value: obj[name].clone(),
+                },
+            ));
+        }
+
         if has_message {
-            self.validate_message_form(obj)?;
+            if matches!(self.tool_choice, ToolChoice::Required | ToolChoice::Function(_)) {
+                return Err(ValidationError::ProtocolViolation(
+                    "tool_choice required a tool call but got a message".to_string(),
+                ));
+            }
+            self.validate_message_form(value, obj)?;
             let output = crate::message::ProtocolOutput::Message(crate::message::MessageForm {
                 message: crate::message::MessageContent {
                     content: obj["message"]["content"]
@@ -83,40 +198,57 @@ impl Validator {
             return Ok(output);
         }
 
-        self.validate_tool_call_form(obj)?;
-        let output = crate::message::ProtocolOutput::ToolCall(crate::message::ToolCallWrapper {
-            tool_call: crate::message::ToolCallForm {
-                tool: obj["tool_call"]["tool"]
-                    .as_str()
-                    .ok_or_else(|| {
-                        ValidationError::InvalidFieldType(
-                            "tool_call.tool must be a string".to_string(),
-                        )
-                    })?
-                    .to_string(),
-                args: obj["tool_call"]["args"].clone(),
-            },
-        });
+        if has_tool_call {
+            self.validate_tool_call_form(value, obj)?;
+            let tool_call = parse_tool_call(&obj["tool_call"], "tool_call")?;
+            self.enforce_tool_choice(&tool_call.tool)?;
+            self.enforce_registry(&tool_call.tool, &tool_call.args)?;
+            return Ok(crate::message::ProtocolOutput::ToolCall(
+                crate::message::ToolCallWrapper { tool_call },
+            ));
+        }
+
+        if has_tool_calls {
+            self.validate_tool_calls_form(obj)?;
+            let tool_calls = obj["tool_calls"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .enumerate()
+                .map(|(i, entry)| parse_tool_call(entry, &format!("tool_calls[{}]", i)))
+                .collect::<Result<Vec<_>, _>>()?;
+            for tool_call in &tool_calls {
+                self.enforce_tool_choice(&tool_call.tool)?;
+                self.enforce_registry(&tool_call.tool, &tool_call.args)?;
+            }
+            return Ok(crate::message::ProtocolOutput::ToolCalls(
+                crate::message::ToolCallsWrapper { tool_calls },
+            ));
+        }
+
+        self.validate_tool_result_form(obj)?;
+        let tool_result = &obj["tool_result"];
+        let output =
+            crate::message::ProtocolOutput::ToolResult(crate::message::ToolResultWrapper {
+                tool_result: crate::message::ToolResultForm {
+                    id: parse_id(tool_result.get("id"))?,
+                    output: tool_result.get("output").cloned(),
+                    error: tool_result.get("error").cloned(),
+                },
+            });
         Ok(output)
     }
 
     fn validate_message_form(
         &self,
+        value: &serde_json::Value,
         obj: &serde_json::Map<String, serde_json::Value>,
     ) -> Result<(), ValidationError> {
-        let message = obj
-            .get("message")
-            .ok_or_else(|| ValidationError::MissingField("message".to_string()))?;
-
-        let message_obj = message.as_object().ok_or_else(|| {
-            ValidationError::ProtocolViolation("message must be an object".to_string())
-        })?;
-
-        if !message_obj.contains_key("content") {
-            return Err(ValidationError::MissingField("message.content".to_string()));
-        }
+        check_schema(&self.message_schema, value)?;
 
-        let content = message_obj["content"].as_str().ok_or_else(|| {
+        // Structural shape is now guaranteed by `message_schema`; layer the
+        // crate's own semantic rule (non-empty content) on top.
+        let content = obj["message"]["content"].as_str().ok_or_else(|| {
             ValidationError::InvalidFieldType("message.content must be a string".to_string())
         })?;
 
@@ -131,43 +263,131 @@ impl Validator {
 
     fn validate_tool_call_form(
         &self,
+        value: &serde_json::Value,
         obj: &serde_json::Map<String, serde_json::Value>,
     ) -> Result<(), ValidationError> {
-        let tool_call = obj
-            .get("tool_call")
-            .ok_or_else(|| ValidationError::MissingField("tool_call".to_string()))?;
+        check_schema(&self.tool_call_schema, value)?;
 
-        let tc_obj = tool_call.as_object().ok_or_else(|| {
-            ValidationError::ProtocolViolation("tool_call must be an object".to_string())
+        // Structural shape is now guaranteed by `tool_call_schema`; layer
+        // the crate's own semantic rule (non-empty tool name) on top.
+        let tool = obj["tool_call"]["tool"].as_str().ok_or_else(|| {
+            ValidationError::InvalidFieldType("tool_call.tool must be a string".to_string())
         })?;
 
-        if !tc_obj.contains_key("tool") {
-            return Err(ValidationError::MissingField("tool_call.tool".to_string()));
+        if tool.is_empty() {
+            return Err(ValidationError::ProtocolViolation(
+                "tool_call.tool must not be empty".to_string(),
+            ));
         }
 
-        let tool = tc_obj["tool"].as_str().ok_or_else(|| {
-            ValidationError::InvalidFieldType("tool_call.tool must be a string".to_string())
+        Ok(())
+    }
+
+    fn validate_tool_calls_form(
+        &self,
+        obj: &serde_json::Map<String, serde_json::Value>,
+    ) -> Result<(), ValidationError> {
+        let tool_calls = obj
+            .get("tool_calls")
+            .ok_or_else(|| ValidationError::MissingField("tool_calls".to_string()))?;
+
+        let entries = tool_calls.as_array().ok_or_else(|| {
+            ValidationError::InvalidFieldType("tool_calls must be an array".to_string())
         })?;
 
-        if tool.is_empty() {
+        if entries.is_empty() {
             return Err(ValidationError::ProtocolViolation(
-                "tool_call.tool must not be empty".to_string(),
+                "tool_calls must not be empty".to_string(),
             ));
         }
 
-        if !tc_obj.contains_key("args") {
-            return Err(ValidationError::MissingField("tool_call.args".to_string()));
+        let mut seen_ids = std::collections::HashSet::new();
+        for (i, entry) in entries.iter().enumerate() {
+            validate_tool_call_object(entry, &format!("tool_calls[{}]", i))?;
+            if let Some(id) = parse_optional_id(entry.get("id"))? {
+                if !id.is_null() && !seen_ids.insert(id) {
+                    return Err(ValidationError::ProtocolViolation(
+                        "tool_calls ids must be unique across the batch".to_string(),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_tool_result_form(
+        &self,
+        obj: &serde_json::Map<String, serde_json::Value>,
+    ) -> Result<(), ValidationError> {
+        let tool_result = obj
+            .get("tool_result")
+            .ok_or_else(|| ValidationError::MissingField("tool_result".to_string()))?;
+
+        let tr_obj = tool_result.as_object().ok_or_else(|| {
+            ValidationError::ProtocolViolation("tool_result must be an object".to_string())
+        })?;
+
+        if !tr_obj.contains_key("id") {
+            return Err(ValidationError::MissingField("tool_result.id".to_string()));
+        }
+
+        if parse_id(tr_obj.get("id"))?.is_null() {
+            return Err(ValidationError::ProtocolViolation(
+                "tool_result.id must not be null".to_string(),
+            ));
         }
 
-        if !tc_obj["args"].is_object() {
-            return Err(ValidationError::InvalidFieldType(
-                "tool_call.args must be an object".to_string(),
+        let has_output = tr_obj.contains_key("output");
+        let has_error = tr_obj.contains_key("error");
+
+        if has_output == has_error {
+            return Err(ValidationError::ProtocolViolation(
+                "tool_result must contain exactly one of 'output' or 'error'".to_string(),
             ));
         }
 
         Ok(())
     }
 
+    fn enforce_tool_choice(&self, tool: &str) -> Result<(), ValidationError> {
+        match &self.tool_choice {
+            ToolChoice::Auto | ToolChoice::Required => Ok(()),
+            ToolChoice::None => Err(ValidationError::ProtocolViolation(
+                "tool_choice forbade a tool call but got one".to_string(),
+            )),
+            ToolChoice::Function(name) if name == tool => Ok(()),
+            ToolChoice::Function(name) => Err(ValidationError::ProtocolViolation(format!(
+                "tool_choice pinned '{}' but got '{}'",
+                name, tool
+            ))),
+        }
+    }
+
+    fn enforce_registry(
+        &self,
+        tool: &str,
+        args: &serde_json::Value,
+    ) -> Result<(), ValidationError> {
+        let Some(registry) = &self.registry else {
+            return Ok(());
+        };
+
+        let schema = registry
+            .schema_for(tool)
+            .ok_or_else(|| ValidationError::UnknownCapability(tool.to_string()))?;
+
+        if let Some(error) = schema.iter_errors(args).next() {
+            return Err(ValidationError::ArgsSchemaViolation {
+                tool: tool.to_string(),
+                path: error.instance_path.to_string(),
+                reason: error.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
     pub fn validate_str(
         &self,
         input: &str,
@@ -175,6 +395,43 @@ impl Validator {
         let value: serde_json::Value = serde_json::from_str(input)?;
         self.validate(&value)
     }
+
+    /// Validates a single line without ever returning `Err`: a blank line
+    /// becomes [`ParsedOutcome::Empty`], a well-formed line becomes
+    /// [`ParsedOutcome::Content`], and anything else becomes
+    /// [`ParsedOutcome::Malformed`] carrying the original text alongside
+    /// the error, so a caller can log and skip it instead of losing the
+    /// whole batch.
+    pub fn validate_lenient(&self, input: &str) -> ParsedOutcome {
+        if input.trim().is_empty() {
+            return ParsedOutcome::Empty;
+        }
+
+        match self.validate_str(input) {
+            Ok(output) => ParsedOutcome::Content(output),
+            Err(error) => ParsedOutcome::Malformed {
+                raw: input.to_string(),
+                error,
+            },
+        }
+    }
+
+    /// Classifies every item in a stream or batch of model outputs,
+    /// without short-circuiting on the first malformed one.
+    pub fn validate_batch<'a>(
+        &self,
+        lines: impl Iterator<Item = &'a str>,
+    ) -> Vec<ParsedOutcome> {
+        lines.map(|line| self.validate_lenient(line)).collect()
+    }
+}
+
+/// The outcome of tolerantly parsing one line of a batch or stream.
+#[derive(Debug)]
+pub enum ParsedOutcome {
+    Content(crate::message::ProtocolOutput),
+    Malformed { raw: String, error: ValidationError },
+    Empty,
 }
 
 impl Default for Validator {
@@ -183,6 +440,110 @@ impl Default for Validator {
     }
 }
 
+/// Compiles one of the crate's own built-in schema constants from
+/// [`crate::schema`]. These are authored in-tree and covered by the test
+/// suite, so a compilation failure here is a bug in the crate, not
+/// something a caller needs to recover from.
+fn compile_builtin_schema(schema_str: &str) -> jsonschema::Validator {
+    let schema_value: serde_json::Value =
+        serde_json::from_str(schema_str).expect("built-in schema must be valid JSON");
+    jsonschema::validator_for(&schema_value).expect("built-in schema must compile")
+}
+
+/// Runs a compiled JSON Schema against `value`, surfacing the first
+/// violation as a [`ValidationError::ProtocolViolation`].
+fn check_schema(
+    schema: &jsonschema::Validator,
+    value: &serde_json::Value,
+) -> Result<(), ValidationError> {
+    if let Some(error) = schema.iter_errors(value).next() {
+        return Err(ValidationError::ProtocolViolation(error.to_string()));
+    }
+    Ok(())
+}
+
+/// Validates a bare tool call object (the value under `tool_call`, or an
+/// entry of a `tool_calls` array), without unwrapping any outer key.
+fn validate_tool_call_object(
+    value: &serde_json::Value,
+    path: &str,
+) -> Result<(), ValidationError> {
+    let tc_obj = value.as_object().ok_or_else(|| {
+        ValidationError::ProtocolViolation(format!("{} must be an object", path))
+    })?;
+
+    if !tc_obj.contains_key("tool") {
+        return Err(ValidationError::MissingField(format!("{}.tool", path)));
+    }
+
+    let tool = tc_obj["tool"].as_str().ok_or_else(|| {
+        ValidationError::InvalidFieldType(format!("{}.tool must be a string", path))
+    })?;
+
+    if tool.is_empty() {
+        return Err(ValidationError::ProtocolViolation(format!(
+            "{}.tool must not be empty",
+            path
+        )));
+    }
+
+    if !tc_obj.contains_key("args") {
+        return Err(ValidationError::MissingField(format!("{}.args", path)));
+    }
+
+    if !tc_obj["args"].is_object() {
+        return Err(ValidationError::InvalidFieldType(format!(
+            "{}.args must be an object",
+            path
+        )));
+    }
+
+    Ok(())
+}
+
+/// Builds a [`crate::message::ToolCallForm`] from an already-validated tool
+/// call object.
+fn parse_tool_call(
+    value: &serde_json::Value,
+    path: &str,
+) -> Result<crate::message::ToolCallForm, ValidationError> {
+    Ok(crate::message::ToolCallForm {
+        id: parse_optional_id(value.get("id"))?,
+        tool: value["tool"]
+            .as_str()
+            .ok_or_else(|| {
+                ValidationError::InvalidFieldType(format!("{}.tool must be a string", path))
+            })?
+            .to_string(),
+        args: value["args"].clone(),
+    })
+}
+
+/// Parses a required correlation `id` field, rejecting values that are
+/// neither an integer that fits in an `i64`, a string, nor `null`.
+fn parse_id(value: Option<&serde_json::Value>) -> Result<crate::message::Id, ValidationError> {
+    match value {
+        None | Some(serde_json::Value::Null) => Ok(crate::message::Id::Null),
+        Some(serde_json::Value::String(s)) => Ok(crate::message::Id::String(s.clone())),
+        Some(serde_json::Value::Number(n)) if n.is_i64() => {
+            Ok(crate::message::Id::Number(n.as_i64().unwrap()))
+        }
+        Some(_) => Err(ValidationError::InvalidFieldType(
+            "id must be an integer, a string, or null".to_string(),
+        )),
+    }
+}
+
+/// Parses an optional correlation `id` field, returning `None` when absent.
+fn parse_optional_id(
+    value: Option<&serde_json::Value>,
+) -> Result<Option<crate::message::Id>, ValidationError> {
+    match value {
+        None => Ok(None),
+        some => parse_id(some).map(Some),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -234,4 +595,365 @@ mod tests {
         let result = validator.validate(&input);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_validate_tool_call_with_id() {
+        let validator = Validator::new();
+        let input = serde_json::json!({"tool_call": {"id": 7, "tool": "fs.read", "args": {}}});
+        let output = validator.validate(&input).unwrap();
+        assert_eq!(output.correlation_id(), Some(&crate::message::Id::Number(7)));
+    }
+
+    #[test]
+    fn test_validate_tool_result_with_output() {
+        let validator = Validator::new();
+        let input = serde_json::json!({"tool_result": {"id": "abc", "output": {"ok": true}}});
+        let output = validator.validate(&input);
+        assert!(output.is_ok());
+    }
+
+    #[test]
+    fn test_validate_tool_result_with_error() {
+        let validator = Validator::new();
+        let input = serde_json::json!({"tool_result": {"id": 1, "error": {"message": "failed"}}});
+        let result = validator.validate(&input);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_reject_tool_result_null_id() {
+        let validator = Validator::new();
+        let input = serde_json::json!({"tool_result": {"id": null, "output": {}}});
+        let result = validator.validate(&input);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_reject_tool_result_both_output_and_error() {
+        let validator = Validator::new();
+        let input = serde_json::json!({"tool_result": {"id": 1, "output": {}, "error": {}}});
+        let result = validator.validate(&input);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_reject_tool_result_neither_output_nor_error() {
+        let validator = Validator::new();
+        let input = serde_json::json!({"tool_result": {"id": 1}});
+        let result = validator.validate(&input);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_validate_tool_calls_batch() {
+        let validator = Validator::new();
+        let input = serde_json::json!({"tool_calls": [
+            {"id": 1, "tool": "fs.read", "args": {}},
+            {"id": 2, "tool": "web.get", "args": {"url": "https://example.com"}}
+        ]});
+        let output = validator.validate(&input).unwrap();
+        let calls = output.into_tool_calls().unwrap();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[1].1, "web.get");
+    }
+
+    #[test]
+    fn test_reject_empty_tool_calls_batch() {
+        let validator = Validator::new();
+        let input = serde_json::json!({"tool_calls": []});
+        let result = validator.validate(&input);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_reject_tool_calls_duplicate_ids() {
+        let validator = Validator::new();
+        let input = serde_json::json!({"tool_calls": [
+            {"id": 1, "tool": "fs.read", "args": {}},
+            {"id": 1, "tool": "web.get", "args": {}}
+        ]});
+        let result = validator.validate(&input);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_reject_malformed_entry_in_tool_calls_batch() {
+        let validator = Validator::new();
+        let input = serde_json::json!({"tool_calls": [
+            {"id": 1, "tool": "fs.read", "args": {}},
+            {"id": 2, "tool": "", "args": {}}
+        ]});
+        let result = validator.validate(&input);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_registry_allows_registered_tool_with_valid_args() {
+        let registry = crate::capability::CapabilityRegistry::new()
+            .register(
+                "fs.read",
+                serde_json::json!({
+                    "type": "object",
+                    "properties": {"path": {"type": "string"}},
+                    "required": ["path"]
+                }),
+            )
+            .unwrap();
+        let validator = Validator::new().with_registry(registry);
+        let input = serde_json::json!({"tool_call": {"tool": "fs.read", "args": {"path": "/tmp/x"}}});
+        assert!(validator.validate(&input).is_ok());
+    }
+
+    #[test]
+    fn test_registry_rejects_unknown_tool() {
+        let registry = crate::capability::CapabilityRegistry::new()
+            .register("fs.read", serde_json::json!({"type": "object"}))
+            .unwrap();
+        let validator = Validator::new().with_registry(registry);
+        let input = serde_json::json!({"tool_call": {"tool": "web.get", "args": {}}});
+        let result = validator.validate(&input);
+        assert!(matches!(result, Err(ValidationError::UnknownCapability(_))));
+    }
+
+    #[test]
+    fn test_registry_rejects_args_violating_schema() {
+        let registry = crate::capability::CapabilityRegistry::new()
+            .register(
+                "fs.read",
+                serde_json::json!({
+                    "type": "object",
+                    "properties": {"path": {"type": "string"}},
+                    "required": ["path"]
+                }),
+            )
+            .unwrap();
+        let validator = Validator::new().with_registry(registry);
+        let input = serde_json::json!({"tool_call": {"tool": "fs.read", "args": {}}});
+        let result = validator.validate(&input);
+        assert!(matches!(
+            result,
+            Err(ValidationError::ArgsSchemaViolation { .. })
+        ));
+    }
+
+    #[test]
+    fn test_registry_enforces_full_json_schema_vocabulary() {
+        let registry = crate::capability::CapabilityRegistry::new()
+            .register(
+                "fs.read",
+                serde_json::json!({
+                    "type": "object",
+                    "properties": {"path": {"type": "string", "pattern": "^/"}},
+                    "required": ["path"],
+                    "additionalProperties": false
+                }),
+            )
+            .unwrap();
+        let validator = Validator::new().with_registry(registry);
+
+        let bad_pattern =
+            serde_json::json!({"tool_call": {"tool": "fs.read", "args": {"path": "relative"}}});
+        assert!(matches!(
+            validator.validate(&bad_pattern),
+            Err(ValidationError::ArgsSchemaViolation { .. })
+        ));
+
+        let extra_property = serde_json::json!({"tool_call": {"tool": "fs.read", "args": {"path": "/tmp/x", "extra": 1}}});
+        assert!(matches!(
+            validator.validate(&extra_property),
+            Err(ValidationError::ArgsSchemaViolation { .. })
+        ));
+    }
+
+    #[test]
+    fn test_without_registry_any_tool_name_is_permitted() {
+        let validator = Validator::new();
+        let input = serde_json::json!({"tool_call": {"tool": "anything.goes", "args": {}}});
+        assert!(validator.validate(&input).is_ok());
+    }
+
+    #[test]
+    fn test_tool_choice_none_rejects_tool_call() {
+        let validator = Validator::new().with_tool_choice(ToolChoice::None);
+        let input = serde_json::json!({"tool_call": {"tool": "fs.read", "args": {}}});
+        assert!(validator.validate(&input).is_err());
+    }
+
+    #[test]
+    fn test_tool_choice_none_allows_message() {
+        let validator = Validator::new().with_tool_choice(ToolChoice::None);
+        let input = serde_json::json!({"message": {"content": "hi"}});
+        assert!(validator.validate(&input).is_ok());
+    }
+
+    #[test]
+    fn test_tool_choice_required_rejects_message() {
+        let validator = Validator::new().with_tool_choice(ToolChoice::Required);
+        let input = serde_json::json!({"message": {"content": "hi"}});
+        assert!(validator.validate(&input).is_err());
+    }
+
+    #[test]
+    fn test_tool_choice_function_pins_tool_name() {
+        let validator =
+            Validator::new().with_tool_choice(ToolChoice::Function("fs.read".to_string()));
+        let allowed = serde_json::json!({"tool_call": {"tool": "fs.read", "args": {}}});
+        assert!(validator.validate(&allowed).is_ok());
+
+        let disallowed = serde_json::json!({"tool_call": {"tool": "web.get", "args": {}}});
+        assert!(validator.validate(&disallowed).is_err());
+    }
+
+    #[test]
+    fn test_tool_choice_function_applies_to_batch() {
+        let validator =
+            Validator::new().with_tool_choice(ToolChoice::Function("fs.read".to_string()));
+        let input = serde_json::json!({"tool_calls": [
+            {"id": 1, "tool": "fs.read", "args": {}},
+            {"id": 2, "tool": "web.get", "args": {}}
+        ]});
+        assert!(validator.validate(&input).is_err());
+    }
+
+    #[test]
+    fn test_validate_lenient_content() {
+        let validator = Validator::new();
+        let outcome = validator.validate_lenient(r#"{"message":{"content":"hi"}}"#);
+        assert!(matches!(outcome, ParsedOutcome::Content(_)));
+    }
+
+    #[test]
+    fn test_validate_lenient_malformed() {
+        let validator = Validator::new();
+        let outcome = validator.validate_lenient("not json at all");
+        match outcome {
+            ParsedOutcome::Malformed { raw, .. } => assert_eq!(raw, "not json at all"),
+            _ => panic!("expected Malformed"),
+        }
+    }
+
+    #[test]
+    fn test_validate_lenient_empty() {
+        let validator = Validator::new();
+        assert!(matches!(validator.validate_lenient("   "), ParsedOutcome::Empty));
+    }
+
+    #[test]
+    fn test_message_schema_rejects_non_string_content() {
+        let validator = Validator::new();
+        let input = serde_json::json!({"message": {"content": 5}});
+        let result = validator.validate(&input);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_tool_call_schema_rejects_non_object_args() {
+        let validator = Validator::new();
+        let input = serde_json::json!({"tool_call": {"tool": "fs.read", "args": "nope"}});
+        let result = validator.validate(&input);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_additional_schema_accepts_registered_extension_form() {
+        let validator = Validator::new()
+            .with_additional_schema(
+                "ping",
+                r#"{"type": "object", "properties": {"nonce": {"type": "integer"}}, "required": ["nonce"]}"#,
+            )
+            .unwrap();
+        let input = serde_json::json!({"ping": {"nonce": 1}});
+        let output = validator.validate(&input).unwrap();
+        assert!(output.is_extension());
+    }
+
+    #[test]
+    fn test_additional_schema_rejects_instance_violating_it() {
+        let validator = Validator::new()
+            .with_additional_schema(
+                "ping",
+                r#"{"type": "object", "properties": {"nonce": {"type": "integer"}}, "required": ["nonce"]}"#,
+            )
+            .unwrap();
+        let input = serde_json::json!({"ping": {}});
+        let result = validator.validate(&input);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_with_additional_schema_rejects_invalid_schema_document() {
+        let result = Validator::new().with_additional_schema("ping", "not json");
+        assert!(matches!(result, Err(ValidationError::InvalidSchema(_))));
+    }
+
+    #[test]
+    fn test_with_additional_schema_rejects_builtin_name_collision() {
+        let result = Validator::new().with_additional_schema("message", r#"{"type":"object"}"#);
+        assert!(matches!(result, Err(ValidationError::InvalidSchema(_))));
+    }
+
+    #[test]
+    fn test_with_additional_schema_rejects_duplicate_extension_name() {
+        let result = Validator::new()
+            .with_additional_schema("ping", r#"{"type":"object"}"#)
+            .unwrap()
+            .with_additional_schema("ping", r#"{"type":"object"}"#);
+        assert!(matches!(result, Err(ValidationError::InvalidSchema(_))));
+    }
+
+    #[test]
+    fn test_extension_form_exempt_from_tool_choice_required() {
+        let validator = Validator::new()
+            .with_tool_choice(ToolChoice::Required)
+            .with_additional_schema("ping", r#"{"type":"object"}"#)
+            .unwrap();
+        let input = serde_json::json!({"ping": {"nonce": 1}});
+        assert!(validator.validate(&input).is_ok());
+    }
+
+    #[test]
+    fn test_extension_form_exempt_from_tool_choice_none() {
+        let validator = Validator::new()
+            .with_tool_choice(ToolChoice::None)
+            .with_additional_schema("ping", r#"{"type":"object"}"#)
+            .unwrap();
+        let input = serde_json::json!({"ping": {"nonce": 1}});
+        assert!(validator.validate(&input).is_ok());
+    }
+
+    #[test]
+    fn test_extension_form_exempt_from_tool_choice_function() {
+        let validator = Validator::new()
+            .with_tool_choice(ToolChoice::Function("fs.read".to_string()))
+            .with_additional_schema("ping", r#"{"type":"object"}"#)
+            .unwrap();
+        let input = serde_json::json!({"ping": {"nonce": 1}});
+        assert!(validator.validate(&input).is_ok());
+    }
+
+    #[test]
+    fn test_builtin_form_still_validates_after_unrelated_extension_registered() {
+        let validator = Validator::new()
+            .with_additional_schema("ping", r#"{"type":"object"}"#)
+            .unwrap();
+        let input = serde_json::json!({"message": {"content": "hi"}});
+        assert!(validator.validate(&input).is_ok());
+    }
+
+    #[test]
+    fn test_validate_batch_does_not_short_circuit() {
+        let validator = Validator::new();
+        let lines = vec![
+            r#"{"message":{"content":"hi"}}"#,
+            "garbage",
+            "",
+            r#"{"tool_call":{"tool":"fs.read","args":{}}}"#,
+        ];
+        let outcomes = validator.validate_batch(lines.into_iter());
+        assert_eq!(outcomes.len(), 4);
+        assert!(matches!(outcomes[0], ParsedOutcome::Content(_)));
+        assert!(matches!(outcomes[1], ParsedOutcome::Malformed { .. }));
+        assert!(matches!(outcomes[2], ParsedOutcome::Empty));
+        assert!(matches!(outcomes[3], ParsedOutcome::Content(_)));
+    }
 }